@@ -1,6 +1,18 @@
 // Define a trait
 trait Greeting {
-    fn greet(&self) -> String;
+    // The payload a greeting carries; just needs to be printable
+    type Message: std::fmt::Display;
+
+    fn new(name: String) -> Self
+    where
+        Self: Sized;
+
+    fn greet(&self) -> Self::Message;
+
+    // Provided method built on top of `greet`
+    fn greet_formally(&self) -> String {
+        format!("Good day. {}", self.greet())
+    }
 }
 
 // Define a struct
@@ -11,7 +23,13 @@ struct Person {
 
 // Implement the trait for Person
 impl Greeting for Person {
-    fn greet(&self) -> String {
+    type Message = String;
+
+    fn new(name: String) -> Self {
+        Person { name, age: 0 }
+    }
+
+    fn greet(&self) -> Self::Message {
         format!("Hello, {}! You are {} years old.", self.name, self.age)
     }
 }
@@ -27,7 +45,31 @@ impl Person {
 const DEFAULT_NAME: &str = "World";
 const MAX_AGE: u32 = 150;
 
+// Static dispatch: works for any single type implementing Greeting
+fn announce(entity: &impl Greeting) {
+    println!("{}", entity.greet());
+}
+
+// Dynamic dispatch: lets callers mix different greeter types in one slice.
+// A trait object has to name its associated type, so `Message` is fixed to `String`.
+fn announce_all(entities: &[&dyn Greeting<Message = String>]) {
+    for entity in entities {
+        println!("{}", entity.greet());
+    }
+}
+
+// Factory-style generic code enabled by `Greeting::new`, returning whatever
+// message type the implementor chooses
+fn make_and_greet<T: Greeting>(name: String) -> T::Message {
+    T::new(name).greet()
+}
+
 fn main() {
     let person = Person::new(DEFAULT_NAME.to_string(), 30);
     println!("{}", person.greet());
+
+    announce(&person);
+    announce_all(&[&person]);
+
+    println!("{}", make_and_greet::<Person>(DEFAULT_NAME.to_string()));
 }